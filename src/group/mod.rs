@@ -1,29 +1,52 @@
-use alga::general::AbstractGroup;
-use alga::general::Operator;
-use num::BigInt;
-use num::BigUint;
+//! Group traits and the concrete RSA groups implementing them.
+use crate::util::TypeRep;
+use rug::Integer;
+use std::fmt::Debug;
+use std::hash::Hash;
 
-pub mod class;
-pub mod rsa;
+pub mod rsa100;
+pub mod rsa3x5;
 
-/// Selection of an element in the generating set.
-pub trait Generator<O: Operator>: AbstractGroup<O> {
-  fn generator() -> Self;
-}
+/// A mathematical group.
+///
+/// The `*_` methods take the group's representation (e.g. its modulus) explicitly so that
+/// the public wrappers can supply it from [`TypeRep`], keeping call sites terse.
+pub trait Group: TypeRep + Sized {
+    type Elem: Clone + Debug + Eq + Hash;
+
+    fn id_(rep: &Self::Rep) -> Self::Elem;
+    fn op_(rep: &Self::Rep, a: &Self::Elem, b: &Self::Elem) -> Self::Elem;
+    fn inv_(rep: &Self::Rep, x: &Self::Elem) -> Self::Elem;
+    fn exp_(rep: &Self::Rep, x: &Self::Elem, n: &Integer) -> Self::Elem;
+
+    fn id() -> Self::Elem {
+        Self::id_(Self::rep())
+    }
+
+    fn op(a: &Self::Elem, b: &Self::Elem) -> Self::Elem {
+        Self::op_(Self::rep(), a, b)
+    }
 
-/// Efficient computation of group inverses.
-pub trait Inverse<O: Operator>: Pow<O> {
-  fn efficient_inverse(&self, exp: &BigUint) -> Self;
-  fn pow_signed(&self, exp: &BigInt) -> Self {
-    match exp.to_biguint() {
-      Some(value) => self.pow(&value),
-      None => self.efficient_inverse(&(-exp).to_biguint().expect("negative BigInt expected"))
+    fn inv(x: &Self::Elem) -> Self::Elem {
+        Self::inv_(Self::rep(), x)
     }
-  }
+
+    fn exp(x: &Self::Elem, n: &Integer) -> Self::Elem {
+        Self::exp_(Self::rep(), x, n)
+    }
+}
+
+/// Construction of group elements from other types.
+pub trait ElemFrom<T>: Group {
+    fn elem(t: T) -> Self::Elem;
 }
 
-/// Efficient exponentiation in a group.
-pub trait Pow<O: Operator>: AbstractGroup<O> {
-  // TODO: Write default impl using repeated squaring.
-  fn pow(&self, exp: &BigUint) -> Self;
+/// A group whose order is unknown, such as an RSA group. Provides a canonical element of
+/// (conjecturally) high order to use as a generator.
+pub trait UnknownOrderGroup: Group {
+    fn unknown_order_elem_(rep: &Self::Rep) -> Self::Elem;
+
+    fn unknown_order_elem() -> Self::Elem {
+        Self::unknown_order_elem_(Self::rep())
+    }
 }
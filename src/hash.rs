@@ -0,0 +1,32 @@
+//! Hashing utilities, including hashing to primes for Fiat-Shamir challenges.
+use blake2::{Blake2b512, Digest};
+use rug::integer::Order;
+use rug::Integer;
+use std::hash::{Hash, Hasher};
+
+/// Gathers the bytes produced by [`Hash::hash`] so they can be fed through a single
+/// Blake2b invocation. The `finish` required by [`Hasher`] is unused; callers read the
+/// collected bytes directly.
+#[derive(Default)]
+struct ByteHasher {
+    bytes: Vec<u8>,
+}
+
+impl Hasher for ByteHasher {
+    fn finish(&self) -> u64 {
+        0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+}
+
+/// Hashes the binary encoding of `t` with Blake2b and rejection-samples upward to the
+/// next prime, yielding a deterministic Fiat-Shamir challenge.
+pub fn hash_to_prime<T: Hash>(t: &T) -> Integer {
+    let mut collector = ByteHasher::default();
+    t.hash(&mut collector);
+    let digest = Blake2b512::digest(&collector.bytes);
+    Integer::from_digits(digest.as_slice(), Order::Lsf).next_prime()
+}
@@ -0,0 +1,9 @@
+//! Cryptographic accumulators and verifiable delay functions over groups of unknown
+//! order, following the constructions of Boneh, Bünz and Fisch (BBF).
+#[macro_use]
+extern crate lazy_static;
+
+pub mod group;
+pub mod hash;
+pub mod util;
+pub mod vdf;
@@ -0,0 +1,18 @@
+//! Small helpers shared across the crate.
+use rug::Integer;
+
+/// A type with a canonical, statically-allocated representation, such as a group's
+/// modulus. Lets group operations take their parameters by reference without threading
+/// them through every call site.
+pub trait TypeRep: 'static {
+    type Rep: 'static;
+    fn rep() -> &'static Self::Rep;
+}
+
+/// Convenience constructor for a GMP [`Integer`].
+pub fn int<T>(val: T) -> Integer
+where
+    Integer: From<T>,
+{
+    Integer::from(val)
+}